@@ -0,0 +1,83 @@
+// Per-URL request configuration, allowing `--file` entries to carry an HTTP
+// method, headers (including Authorization), a body, and an expected status
+// (or range) instead of just a bare URL.
+
+use std::collections::HashMap;
+
+use serde::Deserialize;
+
+use crate::log_error;
+
+#[derive(Deserialize, Clone, Debug)]
+#[serde(untagged)]
+pub enum ExpectedStatus {
+    Exact(u16),
+    Range(u16, u16),
+}
+
+impl Default for ExpectedStatus {
+    fn default() -> Self {
+        ExpectedStatus::Range(200, 299)
+    }
+}
+
+impl ExpectedStatus {
+    pub fn matches(&self, code: u16) -> bool {
+        match self {
+            ExpectedStatus::Exact(expected) => *expected == code,
+            ExpectedStatus::Range(min, max) => (*min..=*max).contains(&code),
+        }
+    }
+}
+
+impl std::fmt::Display for ExpectedStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ExpectedStatus::Exact(code) => write!(f, "{}", code),
+            ExpectedStatus::Range(min, max) => write!(f, "{}-{}", min, max),
+        }
+    }
+}
+
+fn default_method() -> String {
+    "GET".to_string()
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct UrlTarget {
+    pub url: String,
+    #[serde(default = "default_method")]
+    pub method: String,
+    #[serde(default)]
+    pub headers: HashMap<String, String>,
+    #[serde(default)]
+    pub body: Option<String>,
+    #[serde(default)]
+    pub expected_status: ExpectedStatus,
+}
+
+impl UrlTarget {
+    pub fn plain(url: String) -> Self {
+        UrlTarget {
+            url,
+            method: default_method(),
+            headers: HashMap::new(),
+            body: None,
+            expected_status: ExpectedStatus::default(),
+        }
+    }
+}
+
+// Parses one `--file` entry: a bare URL, or a JSON object carrying per-request
+// options (method/headers/body/expected_status).
+pub fn parse_line(line: &str) -> UrlTarget {
+    let trimmed = line.trim();
+    if trimmed.starts_with('{') {
+        serde_json::from_str(trimmed).unwrap_or_else(|e| {
+            log_error(&format!("Failed to parse URL config line '{}': {}", trimmed, e));
+            std::process::exit(1);
+        })
+    } else {
+        UrlTarget::plain(trimmed.to_string())
+    }
+}
@@ -0,0 +1,107 @@
+// Optional SQLite-backed history, enabled with `--db <path>`. Each run appends
+// one row per check (status.json is still overwritten every run), so
+// `--history <url>` can report trends across repeated invocations.
+
+use chrono::{DateTime, Utc};
+use rusqlite::{params, Connection};
+
+use crate::{log_error, WebsiteStatus};
+
+pub fn open(path: &str) -> Connection {
+    let conn = Connection::open(path).unwrap_or_else(|e| {
+        log_error(&format!("Failed to open database '{}': {}", path, e));
+        std::process::exit(1);
+    });
+
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS checks (
+            id              INTEGER PRIMARY KEY AUTOINCREMENT,
+            url             TEXT NOT NULL,
+            status_code     INTEGER,
+            error_text      TEXT,
+            response_time_ms INTEGER NOT NULL,
+            timestamp       TEXT NOT NULL
+        )",
+        [],
+    )
+    .unwrap_or_else(|e| {
+        log_error(&format!("Failed to create schema in '{}': {}", path, e));
+        std::process::exit(1);
+    });
+
+    conn
+}
+
+pub fn insert(conn: &Connection, record: &WebsiteStatus) {
+    let (status_code, error_text) = match &record.status {
+        Ok(code) => (Some(*code), None),
+        Err(err) => (None, Some(err.clone())),
+    };
+
+    let result = conn.execute(
+        "INSERT INTO checks (url, status_code, error_text, response_time_ms, timestamp) VALUES (?1, ?2, ?3, ?4, ?5)",
+        params![
+            record.url,
+            status_code,
+            error_text,
+            record.response_time.as_millis() as i64,
+            record.timestamp.to_rfc3339(),
+        ],
+    );
+
+    if let Err(e) = result {
+        log_error(&format!("Failed to persist check for '{}': {}", record.url, e));
+    }
+}
+
+pub struct HistoryRow {
+    pub status_code: Option<u16>,
+    pub error_text: Option<String>,
+    pub response_time_ms: i64,
+    pub timestamp: DateTime<Utc>,
+}
+
+// Prints the last `limit` checks for `url` plus uptime % and average response
+// time over those rows, newest first.
+pub fn print_history(conn: &Connection, url: &str, limit: u32) {
+    let mut stmt = conn
+        .prepare(
+            "SELECT status_code, error_text, response_time_ms, timestamp FROM checks
+             WHERE url = ?1 ORDER BY id DESC LIMIT ?2",
+        )
+        .expect("Failed to prepare history query");
+
+    let rows: Vec<HistoryRow> = stmt
+        .query_map(params![url, limit], |row| {
+            Ok(HistoryRow {
+                status_code: row.get::<_, Option<i64>>(0)?.map(|c| c as u16),
+                error_text: row.get(1)?,
+                response_time_ms: row.get(2)?,
+                timestamp: row.get::<_, String>(3)?.parse().unwrap_or_else(|_| Utc::now()),
+            })
+        })
+        .expect("Failed to run history query")
+        .filter_map(Result::ok)
+        .collect();
+
+    if rows.is_empty() {
+        println!("No history found for {}", url);
+        return;
+    }
+
+    let total = rows.len();
+    let up_count = rows.iter().filter(|r| r.status_code.is_some()).count();            // a stored status_code means the check matched expected_status
+    let uptime_pct = (up_count as f64 / total as f64) * 100.0;
+    let avg_response_ms = rows.iter().map(|r| r.response_time_ms).sum::<i64>() as f64 / total as f64;
+
+    println!("History for {} (last {} checks):", url, total);
+    for row in &rows {
+        let status = match (&row.status_code, &row.error_text) {
+            (Some(code), _) => code.to_string(),
+            (None, Some(err)) => err.clone(),
+            (None, None) => "unknown".to_string(),
+        };
+        println!("  [{}] status={} response_time_ms={}", row.timestamp.to_rfc3339(), status, row.response_time_ms);
+    }
+    println!("Uptime: {:.2}%  Avg response time: {:.1}ms", uptime_pct, avg_response_ms);
+}
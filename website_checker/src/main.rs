@@ -1,12 +1,24 @@
 use std::sync::{mpsc, Arc, Mutex};          // multi-threading
-use std::thread;                          
+use std::thread;
 use std::time::{Duration, Instant};         // for timing requests
+use std::collections::HashMap;
 use chrono::{DateTime, Utc};                // timestamping for websites
-use reqwest::blocking::Client;              
+use rand::Rng;
+use reqwest::blocking::Client;
+use rusqlite::Connection;
 use std::fs::File;                          // file management
-use std::io::BufWriter;                     // writing to file    
+use std::io::BufWriter;                     // writing to file
 use std::env;                               // command-line arguments
-use serde_json::json;                  
+use serde_json::json;
+
+mod config;
+mod db;
+mod notifier;
+mod report;
+mod serve;
+
+use config::UrlTarget;
+use notifier::Notifier;
 
 struct WebsiteStatus {                      // website info that will be printed to status.json file
     url: String,
@@ -15,8 +27,22 @@ struct WebsiteStatus {                      // website info that will be printed
     timestamp: DateTime<Utc>,
 }
 
+// Everything a check cycle needs that stays the same across cycles (shared
+// with --serve so state like previous up/down and the db connection persists
+// between re-checks instead of resetting every interval).
+struct RunConfig {
+    workers: usize,
+    timeout: u64,
+    retries: u32,
+    retry_base_ms: u64,
+    retry_max_ms: u64,
+    notifiers: Arc<Vec<Box<dyn Notifier>>>,
+    previous_states: Arc<Mutex<HashMap<String, bool>>>,
+    db_conn: Option<Arc<Mutex<Connection>>>,
+}
+
 enum Message {                              // for main thread and worker threads to communicate
-    Job(String),
+    Job(UrlTarget),
     Shutdown,
 }
 
@@ -28,12 +54,46 @@ fn log_error(message: &str) {
     eprintln!("[ERROR] [{}] {}", Utc::now(), message);
 }
 
+pub(crate) fn is_up(status: &Result<u16, String>) -> bool {            // Ok means the response matched the target's expected_status
+    status.is_ok()
+}
+
+// Seeds the up/down map from a previous status.json so we can detect transitions
+// across separate invocations of the tool (e.g. run from cron), not just within
+// a single run.
+fn load_previous_states(path: &str) -> HashMap<String, bool> {
+    let mut states = HashMap::new();
+    let Ok(content) = std::fs::read_to_string(path) else {
+        return states;
+    };
+    let Ok(records) = serde_json::from_str::<Vec<serde_json::Value>>(&content) else {
+        return states;
+    };
+    for record in records {
+        let (Some(url), Some(status)) = (record.get("url").and_then(|v| v.as_str()), record.get("status").and_then(|v| v.as_str())) else {
+            continue;
+        };
+        let up = status.parse::<u16>().is_ok();            // a bare numeric status means it matched expected_status
+        states.insert(url.to_string(), up);
+    }
+    states
+}
+
 fn main() {
     let args: Vec<String> = env::args().collect();
-    let mut urls = vec![];
+    let mut urls: Vec<UrlTarget> = vec![];
     let mut workers = num_cpus::get();
     let mut timeout = 5;
     let mut retries = 0;
+    let mut retry_base_ms: u64 = 500;
+    let mut retry_max_ms: u64 = 30_000;
+    let mut notify_config_path: Option<String> = None;
+    let mut db_path: Option<String> = None;
+    let mut history_url: Option<String> = None;
+    let mut history_limit: u32 = 20;
+    let mut report_dir: Option<String> = None;
+    let mut serve_addr: Option<String> = None;
+    let mut interval: u64 = 60;
 
     let mut file_mode = false;  // checks if websites are being read on file
 
@@ -48,7 +108,7 @@ fn main() {
                         Ok(content) => {
                             for line in content.lines() {
                                 if !line.starts_with('#') && !line.trim().is_empty() {
-                                    urls.push(line.to_string());
+                                    urls.push(config::parse_line(line));
                                 }
                             }
                         },
@@ -87,30 +147,149 @@ fn main() {
                     i += 1;
                 }
             }
+            "--notify" => {            // path to a notification sinks config (TOML or JSON)
+                if i + 1 < args.len() {
+                    notify_config_path = Some(args[i + 1].clone());
+                    i += 1;
+                }
+            }
+            "--db" => {            // path to a SQLite database to append check history to
+                if i + 1 < args.len() {
+                    db_path = Some(args[i + 1].clone());
+                    i += 1;
+                }
+            }
+            "--history" => {            // prints history for a URL from the database and exits
+                if i + 1 < args.len() {
+                    history_url = Some(args[i + 1].clone());
+                    i += 1;
+                }
+            }
+            "--history-limit" => {            // how many recent checks to include in --history
+                if i + 1 < args.len() {
+                    history_limit = args[i + 1].parse().unwrap_or_else(|_| {
+                        log_error("Invalid history limit specified.");
+                        std::process::exit(1);
+                    });
+                    i += 1;
+                }
+            }
+            "--retry-base" => {            // base delay in ms for exponential backoff between retries
+                if i + 1 < args.len() {
+                    retry_base_ms = args[i + 1].parse().unwrap_or_else(|_| {
+                        log_error("Invalid retry base specified.");
+                        std::process::exit(1);
+                    });
+                    i += 1;
+                }
+            }
+            "--retry-max" => {            // cap in ms on the backoff delay between retries
+                if i + 1 < args.len() {
+                    retry_max_ms = args[i + 1].parse().unwrap_or_else(|_| {
+                        log_error("Invalid retry max specified.");
+                        std::process::exit(1);
+                    });
+                    i += 1;
+                }
+            }
+            "--report" => {            // directory to write a latency/success report to after all checks complete
+                if i + 1 < args.len() {
+                    report_dir = Some(args[i + 1].clone());
+                    i += 1;
+                }
+            }
+            "--serve" => {            // address to serve live status over HTTP on, e.g. 0.0.0.0:8080
+                if i + 1 < args.len() {
+                    serve_addr = Some(args[i + 1].clone());
+                    i += 1;
+                }
+            }
+            "--interval" => {            // seconds between re-check cycles in --serve mode
+                if i + 1 < args.len() {
+                    interval = args[i + 1].parse().unwrap_or_else(|_| {
+                        log_error("Invalid interval specified.");
+                        std::process::exit(1);
+                    });
+                    i += 1;
+                }
+            }
             arg => {            // adds URLs to the vector. This is the default case
                 if !file_mode {
-                    urls.push(arg.to_string());
+                    urls.push(UrlTarget::plain(arg.to_string()));
                 }
             }
         }
         i += 1; // goes to the next argument
     }
 
+    if let Some(url) = history_url {            // --history is a query subcommand, not a check run
+        let path = db_path.unwrap_or_else(|| {
+            log_error("--history requires --db <path>.");
+            std::process::exit(2);
+        });
+        let conn = db::open(&path);
+        db::print_history(&conn, &url, history_limit);
+        return;
+    }
+
     if urls.is_empty() {            // if no URLS, send error message
-        log_error("No URLs provided. Usage: website_checker [--file <path>] [URL ...] [--workers N] [--timeout S] [--retries N]");
+        log_error("No URLs provided. Usage: website_checker [--file <path>] [URL ...] [--workers N] [--timeout S] [--retries N] [--notify <config>] [--db <path>]");
         std::process::exit(2);
     }
 
     log_info(&format!("Starting check with {} workers, {}s timeout, {} retries", workers, timeout, retries));
 
+    let notifiers: Arc<Vec<Box<dyn Notifier>>> = Arc::new(match &notify_config_path {
+        Some(path) => notifier::build_notifiers(&notifier::load_config(path)),
+        None => vec![],
+    });
+    let previous_states = Arc::new(Mutex::new(load_previous_states("status.json")));
+    let db_conn = db_path.as_deref().map(|path| Arc::new(Mutex::new(db::open(path))));
+
+    let config = RunConfig {
+        workers,
+        timeout,
+        retries,
+        retry_base_ms,
+        retry_max_ms,
+        notifiers,
+        previous_states,
+        db_conn,
+    };
+
+    if let Some(addr) = serve_addr {
+        run_serve(addr, interval, urls, config);
+        return;
+    }
+
+    let results = run_checks(urls, &config);
+    write_results_file(&results);
+
+    if let Some(dir) = &report_dir {
+        report::write(dir, &results, workers);
+    }
+
+    log_info("All checks complete. Results written to status.json.");       // end message
+}
+
+// Runs one full pass over `urls` across a worker-thread pool, the same
+// dispatch model whether called once (one-shot mode) or repeatedly (--serve).
+fn run_checks(urls: Vec<UrlTarget>, config: &RunConfig) -> Vec<WebsiteStatus> {
     let (tx, rx) = mpsc::channel();         // tx is transmitter that sends messages and rx is receiver that gets messages
     let rx = Arc::new(Mutex::new(rx));      // allows rx to be shared by threads
     let results = Arc::new(Mutex::new(vec![]));
     let mut handles = vec![];               // keeps track of all thread handles
 
-    for _ in 0..workers {                   // makes worker threads
+    for _ in 0..config.workers {            // makes worker threads
         let rx_clone = Arc::clone(&rx);
         let results_clone = Arc::clone(&results);
+        let notifiers_clone = Arc::clone(&config.notifiers);
+        let previous_states_clone = Arc::clone(&config.previous_states);
+        let db_conn_clone = config.db_conn.clone();
+        let timeout = config.timeout;
+        let retries = config.retries;
+        let retry_base_ms = config.retry_base_ms;
+        let retry_max_ms = config.retry_max_ms;
         let client = Client::new();
         let handle = thread::spawn(move || {
             loop {
@@ -120,36 +299,80 @@ fn main() {
                 };
 
                 match message {
-                    Ok(Message::Job(url)) => {
-                        println!("[DISPATCH] Sending job for URL: {}", url);
+                    Ok(Message::Job(target)) => {
+                        println!("[DISPATCH] Sending job for URL: {}", target.url);
+                        let method = reqwest::Method::from_bytes(target.method.as_bytes())
+                            .unwrap_or(reqwest::Method::GET);
                         let now = Instant::now();
                         let mut attempts = 0;
                         let mut response;
                         loop {
-                            response = client.get(&url).timeout(Duration::from_secs(timeout as u64)).send();    // tracks response time
+                            let mut request = client
+                                .request(method.clone(), &target.url)
+                                .timeout(Duration::from_secs(timeout));
+                            for (name, value) in &target.headers {
+                                request = request.header(name, value);
+                            }
+                            if let Some(body) = &target.body {
+                                request = request.body(body.clone());
+                            }
+                            response = request.send();    // tracks response time
                             match &response {
                                 Ok(_) => break,
-                                Err(e) if attempts < retries => {
+                                Err(_) if attempts < retries => {
+                                    // Full jitter: delay grows as base * 2^attempt capped at
+                                    // retry_max_ms, then we sleep a uniform random fraction of
+                                    // that so retrying workers don't thunder-herd together.
+                                    let capped_delay_ms = 1u64
+                                        .checked_shl(attempts)
+                                        .map_or(retry_max_ms, |multiplier| retry_base_ms.saturating_mul(multiplier))
+                                        .min(retry_max_ms);
+                                    let jittered_delay_ms = rand::thread_rng().gen_range(0..=capped_delay_ms);
                                     attempts += 1;
-                                    thread::sleep(Duration::from_secs(2));
+                                    thread::sleep(Duration::from_millis(jittered_delay_ms));
                                 },
                                 Err(_) => break,
                             }
                         }
 
                         let status = match response {
-                            Ok(ref resp) => Ok(resp.status().as_u16()),    // successful
-                            Err(e) => Err(format!("Request failed: {}", e.to_string())) // failed, writes down error
+                            Ok(ref resp) => {
+                                let code = resp.status().as_u16();
+                                if target.expected_status.matches(code) {
+                                    Ok(code)
+                                } else {
+                                    Err(format!("Unexpected status: got {}, expected {}", code, target.expected_status))
+                                }
+                            }
+                            Err(e) => Err(format!("Request failed: {}", e)) // failed, writes down error
                         };
 
                         let duration = now.elapsed();
                         let record = WebsiteStatus {
-                            url: url.clone(),
+                            url: target.url.clone(),
                             status,
                             response_time: duration,
                             timestamp: Utc::now(),
                         };
 
+                        // Only fire notifications on an up<->down transition, so a site
+                        // that's merely still down doesn't alert on every poll.
+                        let up = is_up(&record.status);
+                        let transitioned = {
+                            let mut states = previous_states_clone.lock().unwrap();
+                            let prev = states.insert(target.url.clone(), up);
+                            matches!(prev, Some(prev_up) if prev_up != up)
+                        };
+                        if transitioned {
+                            for n in notifiers_clone.iter() {
+                                n.notify(&record);
+                            }
+                        }
+
+                        if let Some(conn) = &db_conn_clone {
+                            db::insert(&conn.lock().unwrap(), &record);
+                        }
+
                         results_clone.lock().unwrap().push(record);
                     }
                     Ok(Message::Shutdown) | Err(_) => break,        // breaks thread
@@ -164,7 +387,7 @@ fn main() {
         tx.send(Message::Job(url)).unwrap();        // every website is an job for worker
     }
 
-    for _ in 0..workers {
+    for _ in 0..config.workers {
         tx.send(Message::Shutdown).unwrap();        // stops worker
     }
 
@@ -172,11 +395,16 @@ fn main() {
         handle.join().expect("Thread panicked");
     }
 
-    // JSON file writing
+    match Arc::try_unwrap(results) {
+        Ok(mutex) => mutex.into_inner().unwrap(),
+        Err(_) => panic!("Worker threads still hold a reference to the results vector"),
+    }
+}
+
+fn write_results_file(results: &[WebsiteStatus]) {
     let file = File::create("status.json").expect("Unable to create file");
-    let writer = BufWriter::new(file); 
+    let writer = BufWriter::new(file);
 
-    let results = results.lock().unwrap();
     let json_results: Vec<_> = results.iter().map(|r| {
         json!({
             "url": r.url,
@@ -190,6 +418,31 @@ fn main() {
     }).collect();
 
     serde_json::to_writer_pretty(writer, &json_results).expect("Failed to write JSON");
+}
 
-    log_info("All checks complete. Results written to status.json.");       // end message
+// Re-checks `urls` on a `--interval` schedule in the background and serves the
+// latest results over HTTP until the process is killed.
+fn run_serve(addr: String, interval: u64, urls: Vec<UrlTarget>, config: RunConfig) {
+    let config = Arc::new(config);
+    let shared_results: Arc<Mutex<Vec<WebsiteStatus>>> = Arc::new(Mutex::new(vec![]));
+    let last_check: Arc<Mutex<Option<DateTime<Utc>>>> = Arc::new(Mutex::new(None));
+
+    {
+        let config = Arc::clone(&config);
+        let shared_results = Arc::clone(&shared_results);
+        let last_check = Arc::clone(&last_check);
+        thread::spawn(move || loop {
+            log_info(&format!("Running check cycle over {} URLs", urls.len()));
+            let results = run_checks(urls.clone(), &config);
+            write_results_file(&results);
+            *shared_results.lock().unwrap() = results;
+            *last_check.lock().unwrap() = Some(Utc::now());
+            thread::sleep(Duration::from_secs(interval));
+        });
+    }
+
+    log_info(&format!("Serving live status on http://{} (refreshing every {}s)", addr, interval));
+    let state = serve::SharedState { results: shared_results, last_check };
+    let runtime = tokio::runtime::Runtime::new().expect("Failed to start async runtime");
+    runtime.block_on(serve::run(&addr, state));
 }
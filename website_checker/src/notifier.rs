@@ -0,0 +1,151 @@
+// Notification sinks fired when a monitored site's status transitions
+// between up and down. Config is a small TOML or JSON file declaring one
+// or more sinks; see `NotifyConfig` for the expected shape.
+
+use reqwest::blocking::Client;
+use serde::Deserialize;
+use serde_json::json;
+
+use crate::{is_up, log_error, WebsiteStatus};
+
+pub trait Notifier: Send + Sync {
+    fn notify(&self, status: &WebsiteStatus);
+}
+
+#[derive(Deserialize, Default)]
+pub struct NotifyConfig {
+    #[serde(default)]
+    pub webhooks: Vec<WebhookSink>,
+    #[serde(default)]
+    pub slack: Vec<SlackSink>,
+    #[serde(default)]
+    pub email: Vec<EmailSink>,
+}
+
+#[derive(Deserialize, Clone)]
+pub struct WebhookSink {
+    pub url: String,
+}
+
+#[derive(Deserialize, Clone)]
+pub struct SlackSink {
+    pub webhook_url: String,
+}
+
+#[derive(Deserialize, Clone)]
+pub struct EmailSink {
+    pub api_url: String,
+    pub api_token: String,
+    pub to: String,
+    pub from: String,
+}
+
+fn status_body(status: &WebsiteStatus) -> serde_json::Value {
+    json!({
+        "url": status.url,
+        "status": match &status.status {
+            Ok(code) => code.to_string(),
+            Err(err) => err.to_string(),
+        },
+        "response_time_ms": status.response_time.as_millis(),
+        "timestamp": status.timestamp.to_rfc3339(),
+    })
+}
+
+struct WebhookNotifier {
+    client: Client,
+    sink: WebhookSink,
+}
+
+impl Notifier for WebhookNotifier {
+    fn notify(&self, status: &WebsiteStatus) {
+        if let Err(e) = self.client.post(&self.sink.url).json(&status_body(status)).send() {
+            log_error(&format!("Webhook notify to {} failed: {}", self.sink.url, e));
+        }
+    }
+}
+
+struct SlackNotifier {
+    client: Client,
+    sink: SlackSink,
+}
+
+impl Notifier for SlackNotifier {
+    fn notify(&self, status: &WebsiteStatus) {
+        let up = is_up(&status.status);
+        let text = format!(
+            "{} {} is {} ({})",
+            if up { ":white_check_mark:" } else { ":rotating_light:" },
+            status.url,
+            if up { "back up" } else { "down" },
+            match &status.status {
+                Ok(code) => code.to_string(),
+                Err(err) => err.to_string(),
+            }
+        );
+        let body = json!({ "text": text });
+        if let Err(e) = self.client.post(&self.sink.webhook_url).json(&body).send() {
+            log_error(&format!("Slack notify to {} failed: {}", self.sink.webhook_url, e));
+        }
+    }
+}
+
+struct EmailNotifier {
+    client: Client,
+    sink: EmailSink,
+}
+
+impl Notifier for EmailNotifier {
+    fn notify(&self, status: &WebsiteStatus) {
+        let up = is_up(&status.status);
+        let body = json!({
+            "to": self.sink.to,
+            "from": self.sink.from,
+            "subject": format!("[website_checker] {} is {}", status.url, if up { "up" } else { "down" }),
+            "body": status_body(status),
+        });
+        let result = self.client
+            .post(&self.sink.api_url)
+            .bearer_auth(&self.sink.api_token)
+            .json(&body)
+            .send();
+        if let Err(e) = result {
+            log_error(&format!("Email notify via {} failed: {}", self.sink.api_url, e));
+        }
+    }
+}
+
+// Loads a notify config from either TOML or JSON, picked by file extension.
+pub fn load_config(path: &str) -> NotifyConfig {
+    let content = std::fs::read_to_string(path).unwrap_or_else(|e| {
+        log_error(&format!("Failed to read notify config '{}': {}", path, e));
+        std::process::exit(1);
+    });
+
+    let parsed = if path.ends_with(".toml") {
+        toml::from_str(&content).map_err(|e| e.to_string())
+    } else {
+        serde_json::from_str(&content).map_err(|e| e.to_string())
+    };
+
+    parsed.unwrap_or_else(|e| {
+        log_error(&format!("Failed to parse notify config '{}': {}", path, e));
+        std::process::exit(1);
+    })
+}
+
+pub fn build_notifiers(config: &NotifyConfig) -> Vec<Box<dyn Notifier>> {
+    let mut notifiers: Vec<Box<dyn Notifier>> = vec![];
+
+    for sink in &config.webhooks {
+        notifiers.push(Box::new(WebhookNotifier { client: Client::new(), sink: sink.clone() }));
+    }
+    for sink in &config.slack {
+        notifiers.push(Box::new(SlackNotifier { client: Client::new(), sink: sink.clone() }));
+    }
+    for sink in &config.email {
+        notifiers.push(Box::new(EmailNotifier { client: Client::new(), sink: sink.clone() }));
+    }
+
+    notifiers
+}
@@ -0,0 +1,94 @@
+// Summary statistics computed across a run's WebsiteStatus records: success
+// and failure counts, min/max/mean response time, and latency percentiles.
+// Written as both a machine-readable JSON report and a short text summary.
+
+use chrono::Utc;
+use serde_json::json;
+
+use crate::{log_error, WebsiteStatus};
+
+fn percentile(sorted_ms: &[u128], p: f64) -> u128 {
+    let n = sorted_ms.len();
+    if n == 0 {
+        return 0;
+    }
+    let index = (p * n as f64).ceil() as usize;
+    let index = index.clamp(1, n);
+    sorted_ms[index - 1]
+}
+
+fn hostname() -> String {
+    std::env::var("HOSTNAME")
+        .ok()
+        .or_else(|| {
+            std::process::Command::new("hostname")
+                .output()
+                .ok()
+                .and_then(|out| String::from_utf8(out.stdout).ok())
+                .map(|s| s.trim().to_string())
+        })
+        .unwrap_or_else(|| "unknown".to_string())
+}
+
+pub fn write(dir: &str, results: &[WebsiteStatus], workers: usize) {
+    if let Err(e) = std::fs::create_dir_all(dir) {
+        log_error(&format!("Failed to create report dir '{}': {}", dir, e));
+        return;
+    }
+
+    let success_count = results.iter().filter(|r| r.status.is_ok()).count();
+    let failure_count = results.len() - success_count;
+
+    let mut response_times_ms: Vec<u128> = results.iter().map(|r| r.response_time.as_millis()).collect();
+    response_times_ms.sort_unstable();
+
+    let (min_ms, max_ms, mean_ms) = if response_times_ms.is_empty() {
+        (0, 0, 0.0)
+    } else {
+        let sum: u128 = response_times_ms.iter().sum();
+        (
+            *response_times_ms.first().unwrap(),
+            *response_times_ms.last().unwrap(),
+            sum as f64 / response_times_ms.len() as f64,
+        )
+    };
+
+    let p50 = percentile(&response_times_ms, 0.50);
+    let p90 = percentile(&response_times_ms, 0.90);
+    let p99 = percentile(&response_times_ms, 0.99);
+
+    let report = json!({
+        "generated_at": Utc::now().to_rfc3339(),
+        "hostname": hostname(),
+        "workers": workers,
+        "total_checks": results.len(),
+        "success_count": success_count,
+        "failure_count": failure_count,
+        "response_time_ms": {
+            "min": min_ms,
+            "max": max_ms,
+            "mean": mean_ms,
+            "p50": p50,
+            "p90": p90,
+            "p99": p99,
+        },
+    });
+
+    let path = format!("{}/report.json", dir.trim_end_matches('/'));
+    match std::fs::File::create(&path) {
+        Ok(file) => {
+            if let Err(e) = serde_json::to_writer_pretty(file, &report) {
+                log_error(&format!("Failed to write report '{}': {}", path, e));
+            }
+        }
+        Err(e) => log_error(&format!("Failed to create report file '{}': {}", path, e)),
+    }
+
+    println!("--- Report summary ---");
+    println!("Checks: {} ({} ok, {} failed)", results.len(), success_count, failure_count);
+    println!(
+        "Response time (ms): min={} max={} mean={:.1} p50={} p90={} p99={}",
+        min_ms, max_ms, mean_ms, p50, p90, p99
+    );
+    println!("Written to {}", path);
+}
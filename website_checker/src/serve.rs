@@ -0,0 +1,94 @@
+// HTTP surface for `--serve` continuous monitoring mode: a JSON status
+// endpoint and a small HTML table, both reading the latest results out of
+// shared state updated by the background check-cycle loop in main.rs.
+
+use std::sync::{Arc, Mutex};
+
+use axum::extract::State;
+use axum::response::{Html, IntoResponse};
+use axum::routing::get;
+use axum::{Json, Router};
+use chrono::{DateTime, Utc};
+use serde_json::{json, Value};
+
+use crate::{log_error, log_info, WebsiteStatus};
+
+#[derive(Clone)]
+pub struct SharedState {
+    pub results: Arc<Mutex<Vec<WebsiteStatus>>>,
+    pub last_check: Arc<Mutex<Option<DateTime<Utc>>>>,
+}
+
+fn status_json(state: &SharedState) -> Value {
+    let results = state.results.lock().unwrap();
+    let last_check = *state.last_check.lock().unwrap();
+
+    json!({
+        "last_check": last_check.map(|t| t.to_rfc3339()),
+        "results": results.iter().map(|r| json!({
+            "url": r.url,
+            "status": match &r.status {
+                Ok(code) => code.to_string(),
+                Err(err) => err.to_string(),
+            },
+            "response_time_ms": r.response_time.as_millis(),
+            "timestamp": r.timestamp.to_rfc3339(),
+        })).collect::<Vec<_>>(),
+    })
+}
+
+async fn status_handler(State(state): State<SharedState>) -> impl IntoResponse {
+    Json(status_json(&state))
+}
+
+async fn index_handler(State(state): State<SharedState>) -> impl IntoResponse {
+    let results = state.results.lock().unwrap();
+    let last_check = *state.last_check.lock().unwrap();
+
+    let rows: String = results
+        .iter()
+        .map(|r| {
+            let (up, status_text) = match &r.status {
+                Ok(code) => (true, code.to_string()),
+                Err(err) => (false, err.clone()),
+            };
+            format!(
+                "<tr><td>{}</td><td style=\"color:{}\">{}</td><td>{}</td><td>{}ms</td></tr>",
+                r.url,
+                if up { "green" } else { "red" },
+                if up { "UP" } else { "DOWN" },
+                status_text,
+                r.response_time.as_millis(),
+            )
+        })
+        .collect();
+
+    Html(format!(
+        "<html><head><title>Website Checker</title></head><body>\
+         <h1>Website Checker</h1>\
+         <p>Last check: {}</p>\
+         <table border=\"1\" cellpadding=\"4\">\
+         <tr><th>URL</th><th>Up?</th><th>Status</th><th>Response time</th></tr>{}\
+         </table></body></html>",
+        last_check.map(|t| t.to_rfc3339()).unwrap_or_else(|| "never".to_string()),
+        rows,
+    ))
+}
+
+pub async fn run(addr: &str, state: SharedState) {
+    let app = Router::new()
+        .route("/", get(index_handler))
+        .route("/status", get(status_handler))
+        .with_state(state);
+
+    let listener = match tokio::net::TcpListener::bind(addr).await {
+        Ok(listener) => listener,
+        Err(e) => {
+            log_error(&format!("Failed to bind {}: {}", addr, e));
+            std::process::exit(1);
+        }
+    };
+
+    log_info(&format!("Listening on http://{}", addr));
+    axum::serve(listener, app).await.expect("HTTP server crashed");
+}